@@ -0,0 +1,102 @@
+// Copyright 2022 Zinc Labs Inc. and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use once_cell::sync::Lazy;
+
+/// A capability a node can run. A node can hold more than one role at once
+/// (the historical, still-default, all-in-one deployment), or be started
+/// with just one by setting the `ZO_NODE_ROLE` environment variable to
+/// `ingest`, `query` or `all`, so ingest and query capacity can be scaled
+/// independently.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Role {
+    Ingester,
+    Querier,
+}
+
+impl std::str::FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "ingest" | "ingester" => Ok(Role::Ingester),
+            "query" | "querier" => Ok(Role::Querier),
+            other => Err(format!("unknown node role: {other}")),
+        }
+    }
+}
+
+/// The roles this node was started with. Defaults to every role (the
+/// previous all-in-one behavior) when `ZO_NODE_ROLE` is unset or empty;
+/// `ZO_NODE_ROLE=all` is equivalent to leaving it unset. A comma-separated
+/// list is also accepted; entries that name no known role are ignored.
+pub static LOCAL_NODE_ROLES: Lazy<Vec<Role>> = Lazy::new(|| match std::env::var("ZO_NODE_ROLE") {
+    Ok(val) if !val.trim().is_empty() => {
+        if val.trim().eq_ignore_ascii_case("all") {
+            vec![Role::Ingester, Role::Querier]
+        } else {
+            val.split(',').filter_map(|v| v.parse().ok()).collect()
+        }
+    }
+    _ => vec![Role::Ingester, Role::Querier],
+});
+
+pub fn is_ingester(roles: &[Role]) -> bool {
+    roles.contains(&Role::Ingester)
+}
+
+pub fn is_querier(roles: &[Role]) -> bool {
+    roles.contains(&Role::Querier)
+}
+
+/// The role labels this node should advertise under in the cluster registry
+/// (e.g. as node metadata in etcd/consul), so a load balancer can route
+/// ingestion and query traffic to separate nodes instead of assuming every
+/// node handles both.
+pub fn node_registration_roles(roles: &[Role]) -> Vec<&'static str> {
+    roles
+        .iter()
+        .map(|r| match r {
+            Role::Ingester => "ingester",
+            Role::Querier => "querier",
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_from_str_accepts_known_aliases() {
+        assert_eq!("ingest".parse::<Role>().unwrap(), Role::Ingester);
+        assert_eq!("ingester".parse::<Role>().unwrap(), Role::Ingester);
+        assert_eq!("query".parse::<Role>().unwrap(), Role::Querier);
+        assert_eq!("querier".parse::<Role>().unwrap(), Role::Querier);
+        assert!("bogus".parse::<Role>().is_err());
+    }
+
+    #[test]
+    fn is_ingester_and_is_querier_check_membership() {
+        let roles = vec![Role::Querier];
+        assert!(!is_ingester(&roles));
+        assert!(is_querier(&roles));
+    }
+
+    #[test]
+    fn node_registration_roles_labels_each_role() {
+        let roles = vec![Role::Ingester, Role::Querier];
+        assert_eq!(node_registration_roles(&roles), vec!["ingester", "querier"]);
+    }
+}
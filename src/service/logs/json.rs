@@ -18,8 +18,9 @@ use arrow_array::{Int64Array, RecordBatch};
 use arrow_schema::{DataType, Field};
 use chrono::{Duration, Utc};
 use datafusion::arrow::datatypes::Schema;
+use flate2::read::{DeflateDecoder, GzDecoder};
 use itertools::Itertools;
-use std::io::Error;
+use std::io::{Error, Read};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -38,21 +39,140 @@ use crate::service::schema::stream_schema_exists;
 #[allow(deprecated)]
 use arrow::json::reader::{Decoder, DecoderOptions};
 
+/// A simple allow/deny condition evaluated against a single field's value,
+/// akin to the `conditions` block of an S3 PostObject policy.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum FieldCondition {
+    Equals { field: String, value: String },
+    StartsWith { field: String, value: String },
+}
+
+impl FieldCondition {
+    fn matches(&self, record: &json::Map<String, json::Value>) -> bool {
+        let (field, value, actual_matches): (&str, &str, fn(&str, &str) -> bool) = match self {
+            FieldCondition::Equals { field, value } => (field, value, |a, b| a == b),
+            FieldCondition::StartsWith { field, value } => (field, value, |a, b| a.starts_with(b)),
+        };
+        match record.get(field).and_then(|v| v.as_str()) {
+            Some(actual) => actual_matches(actual, value),
+            None => false,
+        }
+    }
+}
+
+/// Per-stream ingestion guardrails enforced before a record reaches
+/// `add_valid_record`. Stored alongside stream schema metadata so it can be
+/// managed per org/stream, the same way partition keys and alerts are.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct IngestionPolicy {
+    pub max_body_size: Option<usize>,
+    pub max_record_count: Option<usize>,
+    pub max_field_count: Option<usize>,
+    pub required_fields: Vec<String>,
+    pub conditions: Vec<FieldCondition>,
+}
+
+/// Checks a single flattened record against the stream's ingestion policy.
+/// Returns a descriptive error on the first condition the record violates.
+fn check_ingestion_policy(
+    policy: &IngestionPolicy,
+    record: &json::Map<String, json::Value>,
+) -> Result<(), String> {
+    if let Some(max_field_count) = policy.max_field_count {
+        if record.len() > max_field_count {
+            return Err(format!(
+                "record has {} fields, exceeding the limit of {max_field_count}",
+                record.len()
+            ));
+        }
+    }
+    for required in &policy.required_fields {
+        if !record.contains_key(required) {
+            return Err(format!("record is missing required field [{required}]"));
+        }
+    }
+    for condition in &policy.conditions {
+        if !condition.matches(record) {
+            return Err("record did not satisfy ingestion policy condition".to_string());
+        }
+    }
+    Ok(())
+}
+
+const INGESTION_POLICY_METADATA_KEY: &str = "ingestion_policy";
+
+/// Reads the stream's ingestion policy out of the schema metadata already
+/// fetched into `stream_schema_map` by `stream_schema_exists`, so enforcing
+/// it costs no extra round trip. Returns `None` if the stream has no schema
+/// yet or no policy has been set for it.
+fn get_stream_ingestion_policy(
+    stream_schema_map: &AHashMap<String, Schema>,
+    stream_name: &str,
+) -> Option<IngestionPolicy> {
+    let raw = stream_schema_map
+        .get(stream_name)?
+        .metadata()
+        .get(INGESTION_POLICY_METADATA_KEY)?;
+    json::from_str(raw).ok()
+}
+
+/// Persists `policy` into the stream's schema metadata under the same key
+/// `get_stream_ingestion_policy` reads, so it can be managed per org/stream
+/// the same way partition keys and alerts are. Meant to be called from
+/// whichever admin surface exposes stream settings.
+pub async fn set_stream_ingestion_policy(
+    org_id: &str,
+    stream_name: &str,
+    stream_type: StreamType,
+    policy: &IngestionPolicy,
+) -> Result<(), Error> {
+    let mut stream_schema_map: AHashMap<String, Schema> = AHashMap::new();
+    stream_schema_exists(org_id, stream_name, stream_type, &mut stream_schema_map).await;
+    let schema = stream_schema_map
+        .remove(stream_name)
+        .unwrap_or_else(Schema::empty);
+    let mut metadata = schema.metadata().clone();
+    metadata.insert(
+        INGESTION_POLICY_METADATA_KEY.to_string(),
+        json::to_string(policy).map_err(|e| Error::new(std::io::ErrorKind::InvalidData, e))?,
+    );
+    db::schema::set(
+        org_id,
+        stream_name,
+        stream_type,
+        &schema.with_metadata(metadata),
+        None,
+        false,
+    )
+    .await
+    .map_err(|e| Error::new(std::io::ErrorKind::Other, e.to_string()))
+}
+
 pub async fn ingest(
     org_id: &str,
     in_stream_name: &str,
     body: actix_web::web::Bytes,
     thread_id: usize,
+    content_encoding: Option<&str>,
+    content_type: Option<&str>,
 ) -> Result<HttpResponse, Error> {
     let start = Instant::now();
 
     let stream_name = &crate::service::ingestion::format_stream_name(in_stream_name);
 
-    if !cluster::is_ingester(&cluster::LOCAL_NODE_ROLE) {
+    if !cluster::is_ingester(&cluster::LOCAL_NODE_ROLES) {
+        let err = if cluster::is_querier(&cluster::LOCAL_NODE_ROLES) {
+            // query-only nodes never hold local WAL state, so reject writes early
+            // instead of letting them fail further down the pipeline
+            "this node is running in query-only mode and does not accept ingestion"
+        } else {
+            "not an ingester"
+        };
         return Ok(
             HttpResponse::InternalServerError().json(MetaHttpResponse::error(
                 http::StatusCode::INTERNAL_SERVER_ERROR.into(),
-                "not an ingester".to_string(),
+                err.to_string(),
             )),
         );
     }
@@ -67,20 +187,137 @@ pub async fn ingest(
         );
     }
 
+    let body = match decompress_body(&body, content_encoding) {
+        Ok(body) => body,
+        Err(e) => {
+            return Ok(
+                HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                    http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                    e,
+                )),
+            )
+        }
+    };
+    let body = match decode_charset(&body, content_type) {
+        Ok(body) => body,
+        Err(e) => {
+            return Ok(
+                HttpResponse::BadRequest().json(MetaHttpResponse::error(
+                    http::StatusCode::BAD_REQUEST.into(),
+                    e,
+                )),
+            )
+        }
+    };
+
+    if is_ndjson(content_type, &body) {
+        let body = String::from_utf8(body)
+            .map_err(|e| Error::new(std::io::ErrorKind::InvalidData, e))?;
+        return process_as_json_stream(org_id, stream_name, &body, thread_id, start).await;
+    }
+
     let body_size = body.len();
     let body: Vec<json::Value> = json::from_slice(&body)?;
 
     if CONFIG.common.simple_path {
         process_as_arrow(org_id, stream_name, &body, body_size, thread_id).await
     } else {
-        process_as_json(org_id, stream_name, &body, thread_id, start).await
+        process_as_json(org_id, stream_name, &body, body_size, thread_id, start).await
     }
 }
 
+/// Detects newline-delimited JSON either from an explicit `Content-Type:
+/// application/x-ndjson` or by sniffing the first non-whitespace byte of the
+/// body: a JSON array starts with `[`, while NDJSON starts with a record (`{`).
+fn is_ndjson(content_type: Option<&str>, body: &[u8]) -> bool {
+    let declared_ndjson = content_type
+        .map(|ct| {
+            ct.split(';')
+                .next()
+                .unwrap_or("")
+                .trim()
+                .eq_ignore_ascii_case("application/x-ndjson")
+        })
+        .unwrap_or(false);
+    if declared_ndjson {
+        return true;
+    }
+    !matches!(
+        body.iter().find(|b| !b.is_ascii_whitespace()),
+        None | Some(b'[')
+    )
+}
+
+/// Decompresses the body per `Content-Encoding`, capped at
+/// `CONFIG.limit.ingest_decompressed_size_limit`.
+fn decompress_body(body: &[u8], content_encoding: Option<&str>) -> Result<Vec<u8>, String> {
+    let max_size = CONFIG.limit.ingest_decompressed_size_limit;
+    let mut buf = Vec::with_capacity(body.len());
+    match content_encoding.map(|v| v.trim().to_ascii_lowercase()) {
+        Some(enc) if enc == "gzip" => {
+            GzDecoder::new(body)
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut buf)
+                .map_err(|e| format!("could not decompress gzip body: {e}"))?;
+        }
+        Some(enc) if enc == "deflate" => {
+            DeflateDecoder::new(body)
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut buf)
+                .map_err(|e| format!("could not decompress deflate body: {e}"))?;
+        }
+        Some(enc) if enc == "zstd" => {
+            let mut decoder =
+                zstd::Decoder::new(body).map_err(|e| format!("could not init zstd decoder: {e}"))?;
+            decoder
+                .by_ref()
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut buf)
+                .map_err(|e| format!("could not decompress zstd body: {e}"))?;
+        }
+        Some(enc) if enc == "identity" => return Ok(body.to_vec()),
+        Some(enc) if !enc.is_empty() => {
+            return Err(format!("unsupported content-encoding: {enc}"));
+        }
+        _ => return Ok(body.to_vec()),
+    };
+    if buf.len() as u64 > max_size as u64 {
+        return Err(format!(
+            "decompressed payload exceeds the {max_size} byte limit"
+        ));
+    }
+    Ok(buf)
+}
+
+/// Transcodes the body to UTF-8 per the `charset` parameter of `Content-Type`,
+/// e.g. `text/plain; charset=windows-1252`.
+fn decode_charset(body: &[u8], content_type: Option<&str>) -> Result<Vec<u8>, String> {
+    let charset = content_type.and_then(|ct| {
+        ct.split(';').skip(1).find_map(|param| {
+            param
+                .trim()
+                .strip_prefix("charset=")
+                .map(|v| v.trim().trim_matches(|c| c == '"' || c == '\''))
+        })
+    });
+    let charset = match charset {
+        Some(c) if !c.eq_ignore_ascii_case("utf-8") && !c.eq_ignore_ascii_case("utf8") => c,
+        _ => return Ok(body.to_vec()),
+    };
+    let encoding = encoding_rs::Encoding::for_label(charset.as_bytes())
+        .ok_or_else(|| format!("unsupported charset: {charset}"))?;
+    let (decoded, _, had_errors) = encoding.decode(body);
+    if had_errors {
+        return Err(format!("could not decode body as {charset}"));
+    }
+    Ok(decoded.into_owned().into_bytes())
+}
+
 async fn process_as_json(
     stream_name: &str,
     org_id: &str,
     body: &[json::Value],
+    body_size: usize,
     thread_id: usize,
     start: Instant,
 ) -> Result<HttpResponse, Error> {
@@ -131,6 +368,35 @@ async fn process_as_json(
     crate::service::ingestion::get_stream_alerts(key, &mut stream_alerts_map).await;
     // End get stream alert
 
+    let policy = get_stream_ingestion_policy(&stream_schema_map, stream_name);
+    if let Some(policy) = &policy {
+        if let Some(max) = policy.max_body_size {
+            if body_size > max {
+                return Ok(
+                    HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                        http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                        format!(
+                            "body size {body_size} exceeds the {max} byte ingestion policy limit for stream [{stream_name}]"
+                        ),
+                    )),
+                );
+            }
+        }
+        if let Some(max) = policy.max_record_count {
+            if body.len() > max {
+                return Ok(
+                    HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                        http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                        format!(
+                            "record count {} exceeds the ingestion policy limit of {max} for stream [{stream_name}]",
+                            body.len()
+                        ),
+                    )),
+                );
+            }
+        }
+    }
+
     let mut buf: AHashMap<String, Vec<String>> = AHashMap::new();
     for item in body.iter() {
         //JSON Flattening
@@ -183,6 +449,14 @@ async fn process_as_json(
             json::Value::Number(timestamp.into()),
         );
 
+        if let Some(policy) = &policy {
+            if let Err(e) = check_ingestion_policy(policy, local_val) {
+                stream_status.status.failed += 1;
+                stream_status.status.error = e;
+                continue;
+            }
+        }
+
         let local_trigger = super::add_valid_record(
             StreamMeta {
                 org_id: org_id.to_string(),
@@ -234,6 +508,268 @@ async fn process_as_json(
     )))
 }
 
+// Flush to WAL every this many records or this many bytes of raw NDJSON lines,
+// whichever comes first, so a large bulk push doesn't sit fully buffered in
+// memory before anything hits disk.
+const NDJSON_FLUSH_RECORDS: usize = 1_000;
+const NDJSON_FLUSH_BYTES: usize = 8 * 1024 * 1024;
+
+/// Streaming, NDJSON counterpart of [`process_as_json`]: processes the body
+/// line by line and flushes `buf` to WAL in bounded chunks instead of once at
+/// the end.
+async fn process_as_json_stream(
+    org_id: &str,
+    stream_name: &str,
+    body: &str,
+    thread_id: usize,
+    start: Instant,
+) -> Result<HttpResponse, Error> {
+    let mut min_ts =
+        (Utc::now() + Duration::hours(CONFIG.limit.ingest_allowed_upto)).timestamp_micros();
+
+    #[cfg(feature = "zo_functions")]
+    let mut runtime = crate::service::ingestion::init_functions_runtime();
+
+    let mut stream_schema_map: AHashMap<String, Schema> = AHashMap::new();
+    let mut stream_alerts_map: AHashMap<String, Vec<Alert>> = AHashMap::new();
+    let mut stream_status = StreamStatus {
+        name: stream_name.to_owned(),
+        status: RecordStatus {
+            successful: 0,
+            failed: 0,
+            error: "".to_string(),
+        },
+    };
+
+    let mut trigger: Option<Trigger> = None;
+
+    // Start Register Transforms for stream
+    #[cfg(feature = "zo_functions")]
+    let (local_trans, stream_vrl_map) = crate::service::ingestion::register_stream_transforms(
+        org_id,
+        StreamType::Logs,
+        stream_name,
+    );
+    // End Register Transforms for stream
+
+    let stream_schema = stream_schema_exists(
+        org_id,
+        stream_name,
+        StreamType::Logs,
+        &mut stream_schema_map,
+    )
+    .await;
+    let mut partition_keys: Vec<String> = vec![];
+    if stream_schema.has_partition_keys {
+        partition_keys =
+            crate::service::ingestion::get_stream_partition_keys(stream_name, &stream_schema_map)
+                .await;
+    }
+
+    // Start get stream alerts
+    let key = format!("{}/{}/{}", &org_id, StreamType::Logs, &stream_name);
+    crate::service::ingestion::get_stream_alerts(key, &mut stream_alerts_map).await;
+    // End get stream alert
+
+    let policy = get_stream_ingestion_policy(&stream_schema_map, stream_name);
+    if let Some(policy) = &policy {
+        if let Some(max) = policy.max_body_size {
+            if body.len() > max {
+                return Ok(
+                    HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                        http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                        format!(
+                            "body size {} exceeds the {max} byte ingestion policy limit for stream [{stream_name}]",
+                            body.len()
+                        ),
+                    )),
+                );
+            }
+        }
+    }
+
+    let mut buf: AHashMap<String, Vec<String>> = AHashMap::new();
+    let mut pending_records = 0usize;
+    let mut pending_bytes = 0usize;
+    let mut record_count = 0usize;
+
+    for (line_no, line) in body.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        record_count += 1;
+        if let Some(policy) = &policy {
+            if let Some(max) = policy.max_record_count {
+                if record_count > max {
+                    if !buf.is_empty() {
+                        write_file(
+                            std::mem::take(&mut buf),
+                            thread_id,
+                            org_id,
+                            stream_name,
+                            StreamType::Logs,
+                        );
+                    }
+                    return Ok(
+                        HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                            http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                            format!(
+                                "record count exceeds the ingestion policy limit of {max} for stream [{stream_name}]"
+                            ),
+                        )),
+                    );
+                }
+            }
+        }
+
+        let item: json::Value = match json::from_str(line) {
+            Ok(v) => v,
+            Err(e) => {
+                stream_status.status.failed += 1;
+                if stream_status.status.error.is_empty() {
+                    stream_status.status.error = format!("line {}: {e}", line_no + 1);
+                }
+                continue;
+            }
+        };
+
+        // JSON Flattening
+        let mut value = json::flatten_json_and_format_field(&item);
+
+        #[cfg(feature = "zo_functions")]
+        if !local_trans.is_empty() {
+            value = crate::service::ingestion::apply_stream_transform(
+                &local_trans,
+                &value,
+                &stream_vrl_map,
+                stream_name,
+                &mut runtime,
+            );
+        }
+        #[cfg(feature = "zo_functions")]
+        if value.is_null() || !value.is_object() {
+            stream_status.status.failed += 1; // transform failed or dropped
+            if stream_status.status.error.is_empty() {
+                stream_status.status.error = format!("line {}: transform dropped the record", line_no + 1);
+            }
+            continue;
+        }
+        // End row based transform
+
+        // get json object
+        let local_val = value.as_object_mut().unwrap();
+
+        // handle timestamp
+        let timestamp = match local_val.get(&CONFIG.common.column_timestamp) {
+            Some(v) => match parse_timestamp_micro_from_value(v) {
+                Ok(t) => t,
+                Err(e) => {
+                    stream_status.status.failed += 1;
+                    if stream_status.status.error.is_empty() {
+                        stream_status.status.error = format!("line {}: {e}", line_no + 1);
+                    }
+                    continue;
+                }
+            },
+            None => Utc::now().timestamp_micros(),
+        };
+        // check ingestion time
+        let earlest_time = Utc::now() + Duration::hours(0 - CONFIG.limit.ingest_allowed_upto);
+        if timestamp < earlest_time.timestamp_micros() {
+            stream_status.status.failed += 1; // to old data, just discard
+            if stream_status.status.error.is_empty() {
+                stream_status.status.error =
+                    format!("line {}: {}", line_no + 1, super::get_upto_discard_error());
+            }
+            continue;
+        }
+        if timestamp < min_ts {
+            min_ts = timestamp;
+        }
+        local_val.insert(
+            CONFIG.common.column_timestamp.clone(),
+            json::Value::Number(timestamp.into()),
+        );
+
+        if let Some(policy) = &policy {
+            if let Err(e) = check_ingestion_policy(policy, local_val) {
+                stream_status.status.failed += 1;
+                if stream_status.status.error.is_empty() {
+                    stream_status.status.error = format!("line {}: {e}", line_no + 1);
+                }
+                continue;
+            }
+        }
+
+        pending_bytes += line.len();
+        let local_trigger = super::add_valid_record(
+            StreamMeta {
+                org_id: org_id.to_string(),
+                stream_name: stream_name.to_string(),
+                partition_keys: partition_keys.clone(),
+                stream_alerts_map: stream_alerts_map.clone(),
+            },
+            &mut stream_schema_map,
+            &mut stream_status.status,
+            &mut buf,
+            local_val,
+        )
+        .await;
+
+        if local_trigger.is_some() {
+            trigger = Some(local_trigger.unwrap());
+        }
+        pending_records += 1;
+
+        if pending_records >= NDJSON_FLUSH_RECORDS || pending_bytes >= NDJSON_FLUSH_BYTES {
+            write_file(
+                std::mem::take(&mut buf),
+                thread_id,
+                org_id,
+                stream_name,
+                StreamType::Logs,
+            );
+            pending_records = 0;
+            pending_bytes = 0;
+        }
+    }
+
+    // flush whatever is left under the thresholds
+    if !buf.is_empty() {
+        write_file(buf, thread_id, org_id, stream_name, StreamType::Logs);
+    }
+
+    // only one trigger per request, as it updates etcd
+    super::evaluate_trigger(trigger, stream_alerts_map).await;
+
+    let time = start.elapsed().as_secs_f64();
+    metrics::HTTP_RESPONSE_TIME
+        .with_label_values(&[
+            "/_json",
+            "200",
+            org_id,
+            stream_name,
+            StreamType::Logs.to_string().as_str(),
+        ])
+        .observe(time);
+    metrics::HTTP_INCOMING_REQUESTS
+        .with_label_values(&[
+            "/_json",
+            "200",
+            org_id,
+            stream_name,
+            StreamType::Logs.to_string().as_str(),
+        ])
+        .inc();
+
+    Ok(HttpResponse::Ok().json(IngestionResponse::new(
+        http::StatusCode::OK.into(),
+        vec![stream_status],
+    )))
+}
+
 async fn process_as_arrow(
     org_id: &str,
     stream_name: &String,
@@ -252,6 +788,75 @@ async fn process_as_arrow(
     )
     .await;
 
+    let policy = get_stream_ingestion_policy(&stream_schema_map, stream_name);
+    if let Some(policy) = &policy {
+        if let Some(max) = policy.max_body_size {
+            if body_size > max {
+                return Ok(
+                    HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                        http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                        format!(
+                            "body size {body_size} exceeds the {max} byte ingestion policy limit for stream [{stream_name}]"
+                        ),
+                    )),
+                );
+            }
+        }
+        if let Some(max) = policy.max_record_count {
+            if body.len() > max {
+                return Ok(
+                    HttpResponse::PayloadTooLarge().json(MetaHttpResponse::error(
+                        http::StatusCode::PAYLOAD_TOO_LARGE.into(),
+                        format!(
+                            "record count {} exceeds the ingestion policy limit of {max} for stream [{stream_name}]",
+                            body.len()
+                        ),
+                    )),
+                );
+            }
+        }
+    }
+
+    let mut stream_status = StreamStatus {
+        name: stream_name.to_string(),
+        status: RecordStatus {
+            successful: 0,
+            failed: 0,
+            error: "".to_string(),
+        },
+    };
+
+    // Enforce max_field_count/required_fields/conditions per record, same as
+    // process_as_json, before the survivors go through schema inference.
+    let body: Vec<json::Value> = match &policy {
+        Some(policy) => {
+            let mut filtered = Vec::with_capacity(body.len());
+            for item in body {
+                match item.as_object() {
+                    Some(obj) => match check_ingestion_policy(policy, obj) {
+                        Ok(()) => filtered.push(item.clone()),
+                        Err(e) => {
+                            stream_status.status.failed += 1;
+                            if stream_status.status.error.is_empty() {
+                                stream_status.status.error = e;
+                            }
+                        }
+                    },
+                    None => filtered.push(item.clone()),
+                }
+            }
+            filtered
+        }
+        None => body.to_vec(),
+    };
+    let body: &[json::Value] = &body;
+    if body.is_empty() {
+        return Ok(HttpResponse::Ok().json(IngestionResponse::new(
+            http::StatusCode::OK.into(),
+            vec![stream_status],
+        )));
+    }
+
     let inferred_schema =
         match arrow::json::reader::infer_json_schema_from_iterator(body.iter().map(Ok)) {
             Ok(schema) => schema,
@@ -265,16 +870,30 @@ async fn process_as_arrow(
             }
         };
 
+    let mut added_fields: Vec<String> = vec![];
+    let mut changed_fields: Vec<String> = vec![];
     let mut schema = match stream_schema_map.get(stream_name) {
         Some(existing_schema) => {
             if existing_schema.fields().is_empty() {
+                added_fields.extend(inferred_schema.fields().iter().map(|f| f.name().clone()));
                 inferred_schema
             } else {
                 match crate::service::schema::try_merge(vec![
                     existing_schema.clone(),
                     inferred_schema.clone(),
                 ]) {
-                    Ok(_) => existing_schema.clone(),
+                    Ok(merged) => {
+                        for field in merged.fields() {
+                            match existing_schema.field_with_name(field.name()) {
+                                Ok(existing_field) if existing_field.data_type() != field.data_type() => {
+                                    changed_fields.push(field.name().clone());
+                                }
+                                Ok(_) => {}
+                                Err(_) => added_fields.push(field.name().clone()),
+                            }
+                        }
+                        merged
+                    }
                     Err(e) => {
                         return Ok(HttpResponse::InternalServerError().json(
                             MetaHttpResponse::error(
@@ -286,7 +905,10 @@ async fn process_as_arrow(
                 }
             }
         }
-        None => inferred_schema,
+        None => {
+            added_fields.extend(inferred_schema.fields().iter().map(|f| f.name().clone()));
+            inferred_schema
+        }
     };
 
     match schema.field_with_name(&CONFIG.common.column_timestamp) {
@@ -307,24 +929,55 @@ async fn process_as_arrow(
     );
 
     let batch = match reader.next_batch(&mut value_iter.map(Ok)) {
-        Ok(Some(batch)) => batch,
-        Err(_) => {
-            return Ok(
-                HttpResponse::InternalServerError().json(MetaHttpResponse::error(
-                    http::StatusCode::BAD_REQUEST.into(),
-                    format!("Could not process request for [{}]", stream_name),
-                )),
-            )
+        Ok(Some(batch)) => {
+            stream_status.status.successful += batch.num_rows() as _;
+            batch
         }
+        // the batch as a whole didn't coerce to the schema; fall back to decoding
+        // row by row so a handful of bad records don't sink the whole request
+        Err(_) => match decode_rows_partially(&schema, body) {
+            Some((batch, failed)) => {
+                stream_status.status.successful += batch.num_rows() as _;
+                stream_status.status.failed += failed as _;
+                if failed > 0 {
+                    stream_status.status.error =
+                        format!("{failed} record(s) could not be coerced to the stream schema");
+                }
+                batch
+            }
+            None => {
+                return Ok(
+                    HttpResponse::InternalServerError().json(MetaHttpResponse::error(
+                        http::StatusCode::BAD_REQUEST.into(),
+                        format!("Could not process request for [{}]", stream_name),
+                    )),
+                )
+            }
+        },
         Ok(None) => unreachable!("all records are added to one rb"),
     };
 
+    if !added_fields.is_empty() || !changed_fields.is_empty() {
+        let drift_msg = format!(
+            "schema updated for [{stream_name}]: added fields {added_fields:?}, changed fields {changed_fields:?}"
+        );
+        stream_status.status.error = append_status_error(stream_status.status.error, &drift_msg);
+    }
+
     let mut final_arrays = batch.columns().iter().map(Arc::clone).collect_vec();
     final_arrays[0] = Arc::new(Int64Array::from_value(ts, batch.num_rows()));
 
     let fb = RecordBatch::try_new(schema.clone().into(), final_arrays).unwrap();
     let hour_key = Utc::now().format("%Y_%m_%d_%H").to_string();
 
+    // decode_rows_partially may have dropped rows, so scale body_size down to
+    // the fraction of the request that actually made it into `fb`.
+    let written_size = if fb.num_rows() == body.len() {
+        body_size
+    } else {
+        ((body_size as u128 * fb.num_rows() as u128) / body.len().max(1) as u128) as usize
+    };
+
     let rw_file = crate::infra::wal::get_or_create_arrow(
         thread_id,
         org_id,
@@ -333,7 +986,7 @@ async fn process_as_arrow(
         &hour_key,
         CONFIG.common.wal_memory_mode_enabled,
     );
-    rw_file.write_for_schema(&schema, fb, body_size);
+    rw_file.write_for_schema(&schema, fb, written_size);
 
     if !stream_schema.has_fields {
         let mut metadata = schema.metadata().clone();
@@ -369,5 +1022,202 @@ async fn process_as_arrow(
         ])
         .inc();
 
-    Ok(HttpResponse::Ok().json(IngestionResponse::new(http::StatusCode::OK.into(), vec![])))
+    Ok(HttpResponse::Ok().json(IngestionResponse::new(
+        http::StatusCode::OK.into(),
+        vec![stream_status],
+    )))
+}
+
+/// Appends `addition` to `existing` instead of discarding one or the other,
+/// so e.g. a coercion-failure message and a schema-drift message can both
+/// surface on the same `StreamStatus`.
+fn append_status_error(existing: String, addition: &str) -> String {
+    if existing.is_empty() {
+        addition.to_string()
+    } else {
+        format!("{existing}; {addition}")
+    }
+}
+
+/// Decodes `body` against `schema` row by row, dropping rows that fail type
+/// coercion. Returns the merged batch of surviving rows plus how many were
+/// dropped, or `None` if every row failed.
+fn decode_rows_partially(schema: &Schema, body: &[json::Value]) -> Option<(RecordBatch, usize)> {
+    let mut valid_rows = Vec::with_capacity(body.len());
+    let mut failed = 0usize;
+    for row in body {
+        #[allow(deprecated)]
+        let row_reader = Decoder::new(schema.clone().into(), DecoderOptions::new().with_batch_size(1));
+        match row_reader.next_batch(&mut std::iter::once(Ok(row.clone()))) {
+            Ok(Some(_)) => valid_rows.push(row.clone()),
+            _ => failed += 1,
+        }
+    }
+    if valid_rows.is_empty() {
+        return None;
+    }
+
+    let batch_size = arrow::util::bit_util::round_upto_multiple_of_64(valid_rows.len());
+    #[allow(deprecated)]
+    let reader = Decoder::new(
+        schema.clone().into(),
+        DecoderOptions::new().with_batch_size(batch_size),
+    );
+    match reader.next_batch(&mut valid_rows.into_iter().map(Ok)) {
+        Ok(Some(batch)) => Some((batch, failed)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_schema(policy: &IngestionPolicy) -> Schema {
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert(
+            INGESTION_POLICY_METADATA_KEY.to_string(),
+            json::to_string(policy).unwrap(),
+        );
+        Schema::new_with_metadata(vec![], metadata)
+    }
+
+    #[test]
+    fn get_stream_ingestion_policy_reads_schema_metadata() {
+        let policy = IngestionPolicy {
+            max_body_size: Some(1024),
+            ..Default::default()
+        };
+        let mut stream_schema_map = AHashMap::new();
+        stream_schema_map.insert("logs".to_string(), policy_schema(&policy));
+
+        let found = get_stream_ingestion_policy(&stream_schema_map, "logs").unwrap();
+        assert_eq!(found.max_body_size, Some(1024));
+    }
+
+    #[test]
+    fn get_stream_ingestion_policy_missing_stream_is_none() {
+        let stream_schema_map = AHashMap::new();
+        assert!(get_stream_ingestion_policy(&stream_schema_map, "logs").is_none());
+    }
+
+    #[test]
+    fn check_ingestion_policy_rejects_missing_required_field() {
+        let policy = IngestionPolicy {
+            required_fields: vec!["user".to_string()],
+            ..Default::default()
+        };
+        let record = json::from_str::<json::Value>(r#"{"msg": "hi"}"#)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .clone();
+        assert!(check_ingestion_policy(&policy, &record).is_err());
+    }
+
+    #[test]
+    fn append_status_error_keeps_both_messages() {
+        assert_eq!(append_status_error(String::new(), "b"), "b");
+        assert_eq!(append_status_error("a".to_string(), "b"), "a; b");
+    }
+
+    #[test]
+    fn decode_rows_partially_keeps_coercible_rows() {
+        let schema = Schema::new(vec![Field::new("n", DataType::Int64, true)]);
+        let body = vec![
+            json::from_str::<json::Value>(r#"{"n": 1}"#).unwrap(),
+            json::from_str::<json::Value>(r#"{"n": "not a number"}"#).unwrap(),
+            json::from_str::<json::Value>(r#"{"n": 3}"#).unwrap(),
+        ];
+
+        let (batch, failed) = decode_rows_partially(&schema, &body).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(failed, 1);
+    }
+
+    #[test]
+    fn decode_rows_partially_all_rows_failing_is_none() {
+        let schema = Schema::new(vec![Field::new("n", DataType::Int64, true)]);
+        let body = vec![json::from_str::<json::Value>(r#"{"n": "x"}"#).unwrap()];
+        assert!(decode_rows_partially(&schema, &body).is_none());
+    }
+
+    #[test]
+    fn decompress_body_passes_through_uncompressed() {
+        assert_eq!(decompress_body(b"hello", None).unwrap(), b"hello");
+        assert_eq!(decompress_body(b"hello", Some("")).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn decompress_body_rejects_unknown_encoding() {
+        assert!(decompress_body(b"hello", Some("br")).is_err());
+    }
+
+    #[test]
+    fn decompress_body_passes_through_identity() {
+        assert_eq!(decompress_body(b"hello", Some("identity")).unwrap(), b"hello");
+        assert_eq!(
+            decompress_body(b"hello", Some("Identity")).unwrap(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn decode_charset_passes_through_utf8() {
+        assert_eq!(
+            decode_charset(b"hi", Some("application/json; charset=utf-8")).unwrap(),
+            b"hi"
+        );
+        assert_eq!(decode_charset(b"hi", None).unwrap(), b"hi");
+    }
+
+    #[test]
+    fn decode_charset_strips_quotes_around_the_value() {
+        assert_eq!(
+            decode_charset(b"hi", Some("text/plain; charset=\"UTF-8\"")).unwrap(),
+            b"hi"
+        );
+    }
+
+    #[test]
+    fn decode_charset_transcodes_non_utf8_charset() {
+        let latin1 = vec![0xe9]; // 'é' in latin-1
+        let decoded =
+            decode_charset(&latin1, Some("text/plain; charset=iso-8859-1")).unwrap();
+        assert_eq!(String::from_utf8(decoded).unwrap(), "é");
+    }
+
+    #[test]
+    fn is_ndjson_detects_declared_content_type() {
+        assert!(is_ndjson(Some("application/x-ndjson"), b"{}"));
+        assert!(is_ndjson(
+            Some("application/x-ndjson; charset=utf-8"),
+            b"{}"
+        ));
+    }
+
+    #[test]
+    fn is_ndjson_sniffs_when_content_type_is_generic() {
+        assert!(is_ndjson(Some("text/plain"), b"{\"a\":1}\n{\"a\":2}"));
+        assert!(is_ndjson(None, b"{\"a\":1}"));
+        assert!(!is_ndjson(Some("application/json"), b"[{\"a\":1}]"));
+        assert!(!is_ndjson(None, b"  [{\"a\":1}]"));
+    }
+
+    #[test]
+    fn check_ingestion_policy_accepts_matching_condition() {
+        let policy = IngestionPolicy {
+            conditions: vec![FieldCondition::Equals {
+                field: "env".to_string(),
+                value: "prod".to_string(),
+            }],
+            ..Default::default()
+        };
+        let record = json::from_str::<json::Value>(r#"{"env": "prod"}"#)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .clone();
+        assert!(check_ingestion_policy(&policy, &record).is_ok());
+    }
 }